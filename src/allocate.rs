@@ -0,0 +1,105 @@
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::MinorUnit;
+
+impl<C: Currency> Money<C> {
+    /// Splits this amount into parts proportional to `ratios`, guaranteeing
+    /// the parts sum back to the original amount exactly — no minor units
+    /// lost or invented.
+    ///
+    /// Uses the largest remainder method: each share starts at
+    /// `floor(minor_unit * weight / total_weight)`, then the minor units
+    /// truncated away are handed out one at a time to the shares with the
+    /// largest remainder, ties broken by position in `ratios`.
+    /// ```
+    /// # use katjing::Money;
+    /// # use katjing::test::SEK;
+    /// let ten_sek = Money::<SEK>::new(10);
+    /// let shares = ten_sek.allocate(&[1, 1, 1]);
+    /// assert_eq!(
+    ///     vec![Money::<SEK>::in_minor_unit(334), Money::<SEK>::in_minor_unit(333), Money::<SEK>::in_minor_unit(333)],
+    ///     shares
+    /// );
+    /// ```
+    pub fn allocate(&self, ratios: &[u32]) -> Vec<Money<C>> {
+        let total_weight: u128 = ratios.iter().map(|&weight| weight as u128).sum();
+        if total_weight == 0 {
+            return ratios.iter().map(|_| Money::in_minor_unit(0)).collect();
+        }
+
+        let mut shares: Vec<MinorUnit> = Vec::with_capacity(ratios.len());
+        let mut remainders: Vec<(usize, u128)> = Vec::with_capacity(ratios.len());
+        let mut allocated: u128 = 0;
+        for (index, &weight) in ratios.iter().enumerate() {
+            let product = self.minor_unit * weight as u128;
+            let share = product / total_weight;
+            shares.push(share);
+            remainders.push((index, product % total_weight));
+            allocated += share;
+        }
+
+        remainders.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        let mut leftover = self.minor_unit - allocated;
+        for (index, _) in remainders {
+            if leftover == 0 {
+                break;
+            }
+            shares[index] += 1;
+            leftover -= 1;
+        }
+
+        shares.into_iter().map(Money::in_minor_unit).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test::SEK;
+    use crate::Money;
+
+    #[test]
+    fn splits_evenly_when_it_divides_exactly() {
+        let ten_sek = Money::<SEK>::new(10);
+        let shares = ten_sek.allocate(&[1, 1]);
+        assert_eq!(
+            vec![Money::<SEK>::new(5), Money::<SEK>::new(5)],
+            shares
+        );
+    }
+
+    #[test]
+    fn gives_the_remainder_to_the_earliest_share_on_ties() {
+        let ten_sek = Money::<SEK>::new(10);
+        let shares = ten_sek.allocate(&[1, 1, 1]);
+        assert_eq!(
+            vec![
+                Money::<SEK>::in_minor_unit(334),
+                Money::<SEK>::in_minor_unit(333),
+                Money::<SEK>::in_minor_unit(333)
+            ],
+            shares
+        );
+    }
+
+    #[test]
+    fn respects_weighting() {
+        let hundred_sek = Money::<SEK>::new(100);
+        let shares = hundred_sek.allocate(&[1, 2, 3]);
+        assert_eq!(
+            vec![
+                Money::<SEK>::in_minor_unit(1667),
+                Money::<SEK>::in_minor_unit(3333),
+                Money::<SEK>::in_minor_unit(5000)
+            ],
+            shares
+        );
+    }
+
+    #[test]
+    fn sums_back_to_the_original_amount() {
+        let amount = Money::<SEK>::in_minor_unit(1001);
+        let shares = amount.allocate(&[1, 1, 1, 1, 1, 1, 1]);
+        let total: crate::MinorUnit = shares.iter().map(|m| m.minor_unit).sum();
+        assert_eq!(1001, total);
+    }
+}
@@ -1,7 +1,22 @@
 pub trait Currency: std::fmt::Debug {
     const ALPHABETIC_CODE: ::iso_currency::Currency;
+
+    /// Number of minor units in one major unit, e.g. `100` for EUR, `1` for
+    /// JPY, `1000` for BHD.
     fn minor_unit() -> u16 {
-        Self::ALPHABETIC_CODE.subunit_fraction().unwrap_or_else(||1)
+        Self::ALPHABETIC_CODE.subunit_fraction().unwrap_or(1)
+    }
+
+    /// Number of digits needed to print the minor unit, e.g. `2` for EUR,
+    /// `0` for JPY, `3` for BHD.
+    fn minor_unit_digits() -> usize {
+        let mut scale = Self::minor_unit();
+        let mut digits = 0;
+        while scale > 1 {
+            scale /= 10;
+            digits += 1;
+        }
+        digits
     }
 }
 
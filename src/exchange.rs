@@ -0,0 +1,111 @@
+use std::{collections::HashMap, marker::PhantomData};
+
+use iso_currency::Currency as IsoCurrency;
+
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::MinorUnit;
+
+/// An exchange rate from `From` to `To`, expressed as how many major units
+/// of `To` one major unit of `From` is worth (e.g. 1 USD ≈ 0.9683 EUR).
+///
+/// Being generic over both currencies makes it a compile error to apply a
+/// `USD`→`EUR` rate to `Money<SEK>`.
+/// ```
+/// # use katjing::{ExchangeRate, Money};
+/// # use katjing::test::{SEK, EUR};
+/// let sek_to_eur = ExchangeRate::<SEK, EUR>::new(0.088);
+/// let ten_sek = Money::<SEK>::new(10);
+/// let in_eur = ten_sek.convert(sek_to_eur);
+/// assert_eq!(Money::<EUR>::in_minor_unit(88), in_eur);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExchangeRate<From: Currency, To: Currency> {
+    rate: f64,
+    from: PhantomData<From>,
+    to: PhantomData<To>,
+}
+
+impl<From: Currency, To: Currency> ExchangeRate<From, To> {
+    /// `rate` is the number of major units of `To` that one major unit of
+    /// `From` buys.
+    pub fn new(rate: f64) -> Self {
+        ExchangeRate {
+            rate,
+            from: PhantomData,
+            to: PhantomData,
+        }
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+}
+
+impl<From: Currency> Money<From> {
+    /// Converts this amount into `Money<To>` using `rate`, rounding to
+    /// `To`'s number of minor units.
+    pub fn convert<To: Currency>(self, rate: ExchangeRate<From, To>) -> Money<To> {
+        let minor_unit = self.minor_unit as f64 * rate.rate() * (To::minor_unit() as f64)
+            / (From::minor_unit() as f64);
+        Money::in_minor_unit(minor_unit.round() as MinorUnit)
+    }
+}
+
+/// A registry of exchange rates keyed by currency code pair, for multi-hop
+/// pipelines that don't know `From`/`To` until runtime. Rates are stored
+/// type-erased and reconstituted as a typed [`ExchangeRate`] on lookup.
+#[derive(Debug, Default)]
+pub struct Exchange {
+    rates: HashMap<(IsoCurrency, IsoCurrency), f64>,
+}
+
+impl Exchange {
+    pub fn new() -> Self {
+        Exchange {
+            rates: HashMap::new(),
+        }
+    }
+
+    pub fn add_or_update_rate<From: Currency, To: Currency>(
+        &mut self,
+        rate: ExchangeRate<From, To>,
+    ) {
+        self.rates
+            .insert((From::ALPHABETIC_CODE, To::ALPHABETIC_CODE), rate.rate());
+    }
+
+    pub fn get_rate<From: Currency, To: Currency>(&self) -> Option<ExchangeRate<From, To>> {
+        self.rates
+            .get(&(From::ALPHABETIC_CODE, To::ALPHABETIC_CODE))
+            .map(|&rate| ExchangeRate::new(rate))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Exchange, ExchangeRate};
+    use crate::test::{EUR, SEK};
+    use crate::Money;
+
+    #[test]
+    fn converts_using_rate() {
+        let ten_sek = Money::<SEK>::new(10);
+        let rate = ExchangeRate::<SEK, EUR>::new(0.088);
+        assert_eq!(Money::<EUR>::in_minor_unit(88), ten_sek.convert(rate));
+    }
+
+    #[test]
+    fn registry_round_trips_a_rate() {
+        let mut exchange = Exchange::new();
+        exchange.add_or_update_rate(ExchangeRate::<SEK, EUR>::new(0.088));
+        let rate = exchange.get_rate::<SEK, EUR>().unwrap();
+        assert_eq!(0.088, rate.rate());
+    }
+
+    #[test]
+    fn registry_has_no_rate_for_unknown_pair() {
+        let exchange = Exchange::new();
+        assert!(exchange.get_rate::<SEK, EUR>().is_none());
+    }
+}
@@ -0,0 +1,117 @@
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::MinorUnit;
+
+/// Controls how [`Money`] is rendered: the currency symbol, whether it goes
+/// before or after the amount, and the digit-group/decimal separators.
+///
+/// ```
+/// # use katjing::{FormatSpec, Money};
+/// # use katjing::test::SEK;
+/// let us_style = FormatSpec::new("$", true, '.', ',');
+/// let amount = Money::<SEK>::new(1000);
+/// assert_eq!(amount.format(&us_style), "$1,000.00");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatSpec {
+    pub symbol: String,
+    pub symbol_first: bool,
+    pub decimal_separator: char,
+    pub group_separator: char,
+}
+
+impl FormatSpec {
+    pub fn new(
+        symbol: impl Into<String>,
+        symbol_first: bool,
+        decimal_separator: char,
+        group_separator: char,
+    ) -> Self {
+        FormatSpec {
+            symbol: symbol.into(),
+            symbol_first,
+            decimal_separator,
+            group_separator,
+        }
+    }
+
+    /// A default spec derived from `C`'s ISO currency code, used as the
+    /// trailing symbol (ISO 4217 defines no printable symbols, only codes),
+    /// with `.` as the decimal separator and `,` for digit grouping.
+    pub fn for_currency<C: Currency>() -> Self {
+        FormatSpec::new(format!(" {}", C::ALPHABETIC_CODE.code()), false, '.', ',')
+    }
+}
+
+impl<C: Currency> Money<C> {
+    /// Renders this amount using `spec`, e.g. `$1,000.42`, `1.000,42 €` or
+    /// `1 000,42 kr`.
+    pub fn format(&self, spec: &FormatSpec) -> String {
+        let scale = C::minor_unit() as MinorUnit;
+        let major = group_digits(self.minor_unit / scale, spec.group_separator);
+        let digits = C::minor_unit_digits();
+        let amount = if digits == 0 {
+            major
+        } else {
+            format!(
+                "{}{}{:0width$}",
+                major,
+                spec.decimal_separator,
+                self.minor_unit % scale,
+                width = digits
+            )
+        };
+        if spec.symbol_first {
+            format!("{}{}", spec.symbol, amount)
+        } else {
+            format!("{}{}", amount, spec.symbol)
+        }
+    }
+}
+
+fn group_digits(value: MinorUnit, separator: char) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.chars().rev().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::FormatSpec;
+    use crate::test::{JPY, SEK};
+    use crate::Money;
+
+    #[test]
+    fn us_style_formats_with_leading_dollar_and_comma_groups() {
+        let spec = FormatSpec::new("$", true, '.', ',');
+        let amount = Money::<SEK>::new(1000);
+        assert_eq!(amount.format(&spec), "$1,000.00");
+    }
+
+    #[test]
+    fn france_style_swaps_separators_and_trails_the_symbol() {
+        let spec = FormatSpec::new("€", false, ',', '.');
+        let amount = Money::<SEK>::new(1000);
+        assert_eq!(amount.format(&spec), "1.000,00€");
+    }
+
+    #[test]
+    fn zero_decimal_currency_has_no_fraction() {
+        let spec = FormatSpec::new("¥", true, '.', ',');
+        let amount = Money::<JPY>::new(5);
+        assert_eq!(amount.format(&spec), "¥5");
+    }
+
+    #[test]
+    fn for_currency_falls_back_to_the_iso_code() {
+        let spec = FormatSpec::for_currency::<SEK>();
+        let amount = Money::<SEK>::in_minor_unit(133);
+        assert_eq!(amount.format(&spec), "1.33 SEK");
+    }
+}
@@ -1,9 +1,18 @@
+mod allocate;
 mod currency;
+mod exchange;
+mod format;
 mod money;
+mod parse;
+mod percent;
 mod price;
 
 pub use crate::price::Price;
 pub use crate::money::Money;
+pub use crate::exchange::{Exchange, ExchangeRate};
+pub use crate::format::FormatSpec;
+pub use crate::parse::ParseMoneyError;
+pub use crate::percent::{Percent, RoundStrategy};
 
 pub use crate::currency::Currency;
 pub type MinorUnit= u128;
@@ -17,8 +26,10 @@ extern crate doc_comment;
 pub mod test {
     use crate::create_currency;
 
-    create_currency!(SEK, 2);    
+    create_currency!(SEK, 2);
     create_currency!(EUR, 2);
+    create_currency!(JPY, 0);
+    create_currency!(BHD, 3);
 }
 
 #[cfg(doctest)]
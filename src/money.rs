@@ -1,4 +1,8 @@
-use std::{fmt::Display, marker::PhantomData};
+use std::{
+    fmt::Display,
+    marker::PhantomData,
+    ops::{Add, Mul, Sub},
+};
 use crate::currency::Currency;
 use crate::MinorUnit;
 
@@ -28,7 +32,7 @@ impl<C: Currency> Money<C> {
     /// let euro:Money<SEK> = one_euro;
     /// ```
     pub fn new(value: MinorUnit) -> Money<C> {
-        Self::in_minor_unit(value * 100)
+        Self::in_minor_unit(value * C::minor_unit() as MinorUnit)
     }
     /// If you want to create money with it's fractional representation
     /// you use *in_minor_unit*
@@ -45,23 +49,94 @@ impl<C: Currency> Money<C> {
             currency: PhantomData,
         }
     }
+
+    /// Adds two amounts of the same currency, returning `None` on overflow
+    /// instead of panicking.
+    pub fn checked_add(&self, other: &Money<C>) -> Option<Money<C>> {
+        self.minor_unit
+            .checked_add(other.minor_unit)
+            .map(Money::in_minor_unit)
+    }
+
+    /// Subtracts `other` from this amount, returning `None` if the result
+    /// would be negative.
+    pub fn checked_sub(&self, other: &Money<C>) -> Option<Money<C>> {
+        self.minor_unit
+            .checked_sub(other.minor_unit)
+            .map(Money::in_minor_unit)
+    }
+
+    /// Multiplies by an integer factor, returning `None` on overflow.
+    pub fn checked_mul(&self, factor: MinorUnit) -> Option<Money<C>> {
+        self.minor_unit
+            .checked_mul(factor)
+            .map(Money::in_minor_unit)
+    }
+
+    /// Multiplies by a decimal ratio (e.g. `0.8` for a 20% discount),
+    /// returning `None` if the result doesn't fit in a [`MinorUnit`].
+    pub fn checked_mul_f64(&self, factor: f64) -> Option<Money<C>> {
+        let result = self.minor_unit as f64 * factor;
+        if result.is_finite() && result >= 0.0 && result <= MinorUnit::MAX as f64 {
+            Some(Money::in_minor_unit(result.round() as MinorUnit))
+        } else {
+            None
+        }
+    }
+}
+
+impl<C: Currency> Add for Money<C> {
+    type Output = Money<C>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("Money addition overflowed")
+    }
+}
+
+impl<C: Currency> Sub for Money<C> {
+    type Output = Money<C>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("Money subtraction underflowed")
+    }
+}
+
+impl<C: Currency> Mul<MinorUnit> for Money<C> {
+    type Output = Money<C>;
+    fn mul(self, rhs: MinorUnit) -> Self::Output {
+        self.checked_mul(rhs).expect("Money multiplication overflowed")
+    }
+}
+
+impl<C: Currency> Mul<f64> for Money<C> {
+    type Output = Money<C>;
+    fn mul(self, rhs: f64) -> Self::Output {
+        self.checked_mul_f64(rhs)
+            .expect("Money multiplication overflowed")
+    }
 }
 
 impl<C: Currency> Display for Money<C> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}.{:02} {}",
-            self.minor_unit / (C::MINOR_UNIT as u128),
-            self.minor_unit % (C::MINOR_UNIT as u128),
-            C::ALPHABETIC_CODE
-        )
+        let scale = C::minor_unit() as MinorUnit;
+        let major = self.minor_unit / scale;
+        let digits = C::minor_unit_digits();
+        if digits == 0 {
+            write!(f, "{} {}", major, C::ALPHABETIC_CODE.code())
+        } else {
+            write!(
+                f,
+                "{}.{:0width$} {}",
+                major,
+                self.minor_unit % scale,
+                C::ALPHABETIC_CODE.code(),
+                width = digits
+            )
+        }
     }
 }
 
 #[cfg(test)]
 mod display {
-    use crate::test::SEK;
+    use crate::test::{BHD, JPY, SEK};
     use crate::Money;
     #[test]
     fn shows_value() {
@@ -74,6 +149,18 @@ mod display {
         let one_thirtythree_sek = Money::<SEK>::in_minor_unit(133);
         assert_eq!(format!("{}", one_thirtythree_sek), "1.33 SEK");
     }
+
+    #[test]
+    fn shows_zero_decimal_currency_without_fraction() {
+        let five_yen = Money::<JPY>::new(5);
+        assert_eq!(format!("{}", five_yen), "5 JPY");
+    }
+
+    #[test]
+    fn shows_three_decimal_currency_padded() {
+        let one_point_five_bhd = Money::<BHD>::in_minor_unit(1500);
+        assert_eq!(format!("{}", one_point_five_bhd), "1.500 BHD");
+    }
 }
 
 #[cfg(test)]
@@ -101,3 +188,56 @@ mod compare {
         assert!(sek_1_31 > sek_1_30);
     }
 }
+
+#[cfg(test)]
+mod arithmetic {
+    use crate::{test::SEK, Money, MinorUnit};
+
+    #[test]
+    fn adds_same_currency() {
+        let one_sek = Money::<SEK>::new(1);
+        let two_sek = Money::<SEK>::new(2);
+        assert_eq!(Money::<SEK>::new(3), one_sek + two_sek);
+    }
+
+    #[test]
+    fn subtracts_same_currency() {
+        let two_sek = Money::<SEK>::new(2);
+        let one_sek = Money::<SEK>::new(1);
+        assert_eq!(Money::<SEK>::new(1), two_sek - one_sek);
+    }
+
+    #[test]
+    fn multiplies_by_integer() {
+        let one_sek = Money::<SEK>::new(1);
+        assert_eq!(Money::<SEK>::new(3), one_sek * 3);
+    }
+
+    #[test]
+    fn multiplies_by_decimal_ratio_for_a_discount() {
+        let ten_sek = Money::<SEK>::new(10);
+        assert_eq!(Money::<SEK>::new(8), ten_sek * 0.8);
+    }
+
+    #[test]
+    fn checked_add_returns_none_on_overflow() {
+        let max = Money::<SEK>::in_minor_unit(MinorUnit::MAX);
+        let one_sek = Money::<SEK>::new(1);
+        assert_eq!(None, max.checked_add(&one_sek));
+    }
+
+    #[test]
+    fn checked_sub_returns_none_on_underflow() {
+        let one_sek = Money::<SEK>::new(1);
+        let two_sek = Money::<SEK>::new(2);
+        assert_eq!(None, one_sek.checked_sub(&two_sek));
+    }
+
+    #[test]
+    #[should_panic(expected = "Money addition overflowed")]
+    fn add_panics_on_overflow() {
+        let max = Money::<SEK>::in_minor_unit(MinorUnit::MAX);
+        let one_sek = Money::<SEK>::new(1);
+        let _ = max + one_sek;
+    }
+}
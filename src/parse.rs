@@ -0,0 +1,141 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::MinorUnit;
+
+/// Error returned when a string cannot be parsed as [`Money`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseMoneyError {
+    #[error("'{0}' does not contain a valid amount")]
+    InvalidAmount(String),
+    #[error("'{0}' has more fractional digits than the currency allows (max {1})")]
+    TooManyFractionalDigits(String, usize),
+}
+
+impl<C: Currency> FromStr for Money<C> {
+    type Err = ParseMoneyError;
+
+    /// Parses locale-formatted monetary strings such as `"$1,000.42"` or
+    /// `"£10,99"`, stripping any currency symbol and thousands separators.
+    /// The last `.` or `,` in the string is taken as the decimal mark.
+    /// ```
+    /// # use katjing::Money;
+    /// # use katjing::test::SEK;
+    /// let amount: Money<SEK> = "1,000.42".parse().unwrap();
+    /// assert_eq!(Money::<SEK>::in_minor_unit(100042), amount);
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let numeric: String = input
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
+            .collect();
+        if numeric.is_empty() {
+            return Err(ParseMoneyError::InvalidAmount(input.to_string()));
+        }
+
+        let (major_part, fractional_part) = match numeric.rfind(['.', ',']) {
+            Some(pos) => (&numeric[..pos], &numeric[pos + 1..]),
+            None => (numeric.as_str(), ""),
+        };
+
+        let max_digits = C::minor_unit_digits();
+        if fractional_part.len() > max_digits {
+            return Err(ParseMoneyError::TooManyFractionalDigits(
+                input.to_string(),
+                max_digits,
+            ));
+        }
+
+        let major_digits: String = major_part.chars().filter(|c| c.is_ascii_digit()).collect();
+        if major_digits.is_empty() && fractional_part.is_empty() {
+            return Err(ParseMoneyError::InvalidAmount(input.to_string()));
+        }
+
+        let major: MinorUnit = if major_digits.is_empty() {
+            0
+        } else {
+            major_digits
+                .parse()
+                .map_err(|_| ParseMoneyError::InvalidAmount(input.to_string()))?
+        };
+
+        let mut fractional_digits = fractional_part.to_string();
+        while fractional_digits.len() < max_digits {
+            fractional_digits.push('0');
+        }
+        let fractional: MinorUnit = if fractional_digits.is_empty() {
+            0
+        } else {
+            fractional_digits
+                .parse()
+                .map_err(|_| ParseMoneyError::InvalidAmount(input.to_string()))?
+        };
+
+        let scale = C::minor_unit() as MinorUnit;
+        Ok(Money::in_minor_unit(major * scale + fractional))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ParseMoneyError;
+    use crate::test::{JPY, SEK};
+    use crate::Money;
+
+    #[test]
+    fn parses_a_dollar_style_amount() {
+        let amount: Money<SEK> = "$1,000.42".parse().unwrap();
+        assert_eq!(Money::<SEK>::in_minor_unit(100042), amount);
+    }
+
+    #[test]
+    fn parses_a_comma_decimal_amount() {
+        let amount: Money<SEK> = "£10,99".parse().unwrap();
+        assert_eq!(Money::<SEK>::in_minor_unit(1099), amount);
+    }
+
+    #[test]
+    fn parses_a_zero_decimal_amount() {
+        let amount: Money<JPY> = "5".parse().unwrap();
+        assert_eq!(Money::<JPY>::new(5), amount);
+    }
+
+    #[test]
+    fn pads_a_short_fraction() {
+        let amount: Money<SEK> = "1.3".parse().unwrap();
+        assert_eq!(Money::<SEK>::in_minor_unit(130), amount);
+    }
+
+    #[test]
+    fn rejects_too_many_fractional_digits() {
+        let result = "1.009".parse::<Money<SEK>>();
+        assert_eq!(
+            Err(ParseMoneyError::TooManyFractionalDigits(
+                "1.009".to_string(),
+                2
+            )),
+            result
+        );
+    }
+
+    #[test]
+    fn rejects_input_without_digits() {
+        let result = "SEK".parse::<Money<SEK>>();
+        assert_eq!(
+            Err(ParseMoneyError::InvalidAmount("SEK".to_string())),
+            result
+        );
+    }
+
+    #[test]
+    fn format_and_parse_round_trip() {
+        let spec = crate::FormatSpec::new("$", true, '.', ',');
+        let amount = Money::<SEK>::in_minor_unit(100042);
+        let formatted = amount.format(&spec);
+        let parsed: Money<SEK> = formatted.parse().unwrap();
+        assert_eq!(amount, parsed);
+    }
+}
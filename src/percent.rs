@@ -0,0 +1,146 @@
+use crate::currency::Currency;
+use crate::money::Money;
+use crate::MinorUnit;
+
+/// A percentage, e.g. `Percent::new(25.0)` represents 25%.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Percent(f64);
+
+impl Percent {
+    pub fn new(percent: f64) -> Self {
+        Percent(percent)
+    }
+
+    pub fn as_ratio(&self) -> f64 {
+        self.0 / 100.0
+    }
+}
+
+/// How to round a fractional minor-unit amount back to a whole minor unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundStrategy {
+    /// Round half away from zero, e.g. `0.5 -> 1`.
+    HalfUp,
+    /// Round half to the nearest even minor unit ("banker's rounding"),
+    /// e.g. `0.5 -> 0`, `1.5 -> 2`.
+    HalfEven,
+    /// Always round down.
+    Floor,
+    /// Always round up.
+    Ceil,
+}
+
+impl RoundStrategy {
+    fn round(&self, value: f64) -> MinorUnit {
+        match self {
+            RoundStrategy::HalfUp => value.round() as MinorUnit,
+            RoundStrategy::HalfEven => {
+                let floor = value.floor();
+                let is_half = (value - floor - 0.5).abs() < f64::EPSILON;
+                let rounded = if is_half {
+                    if floor as i128 % 2 == 0 {
+                        floor
+                    } else {
+                        floor + 1.0
+                    }
+                } else {
+                    value.round()
+                };
+                rounded as MinorUnit
+            }
+            RoundStrategy::Floor => value.floor() as MinorUnit,
+            RoundStrategy::Ceil => value.ceil() as MinorUnit,
+        }
+    }
+}
+
+impl<C: Currency> Money<C> {
+    /// Applies `percent` to this amount at full precision, then rounds back
+    /// to whole minor units using `rounding`.
+    /// ```
+    /// # use katjing::{Money, Percent, RoundStrategy};
+    /// # use katjing::test::SEK;
+    /// let hundred_sek = Money::<SEK>::new(100);
+    /// let twenty_five_percent = hundred_sek.apply_percent(Percent::new(25.0), RoundStrategy::HalfUp);
+    /// assert_eq!(Money::<SEK>::new(25), twenty_five_percent);
+    /// ```
+    pub fn apply_percent(&self, percent: Percent, rounding: RoundStrategy) -> Money<C> {
+        let minor_unit = rounding.round(self.minor_unit as f64 * percent.as_ratio());
+        Money::in_minor_unit(minor_unit)
+    }
+
+    /// Adds tax at `percent` on top of this amount.
+    pub fn add_tax(&self, percent: Percent, rounding: RoundStrategy) -> Money<C> {
+        let tax = self.apply_percent(percent, rounding);
+        Money::in_minor_unit(self.minor_unit + tax.minor_unit)
+    }
+
+    /// Removes `percent` worth of tax from this amount.
+    pub fn subtract_tax(&self, percent: Percent, rounding: RoundStrategy) -> Money<C> {
+        let tax = self.apply_percent(percent, rounding);
+        Money::in_minor_unit(self.minor_unit - tax.minor_unit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Percent, RoundStrategy};
+    use crate::test::SEK;
+    use crate::Money;
+
+    #[test]
+    fn applies_a_percentage() {
+        let hundred_sek = Money::<SEK>::new(100);
+        let result = hundred_sek.apply_percent(Percent::new(25.0), RoundStrategy::HalfUp);
+        assert_eq!(Money::<SEK>::new(25), result);
+    }
+
+    #[test]
+    fn half_up_rounds_away_from_zero() {
+        let one_odd_ore = Money::<SEK>::in_minor_unit(1);
+        let result = one_odd_ore.apply_percent(Percent::new(50.0), RoundStrategy::HalfUp);
+        assert_eq!(Money::<SEK>::in_minor_unit(1), result);
+    }
+
+    #[test]
+    fn half_even_rounds_to_the_nearest_even_minor_unit() {
+        let one_ore = Money::<SEK>::in_minor_unit(1);
+        let three_ore = Money::<SEK>::in_minor_unit(3);
+        assert_eq!(
+            Money::<SEK>::in_minor_unit(0),
+            one_ore.apply_percent(Percent::new(50.0), RoundStrategy::HalfEven)
+        );
+        assert_eq!(
+            Money::<SEK>::in_minor_unit(2),
+            three_ore.apply_percent(Percent::new(50.0), RoundStrategy::HalfEven)
+        );
+    }
+
+    #[test]
+    fn floor_always_rounds_down() {
+        let one_ore = Money::<SEK>::in_minor_unit(1);
+        let result = one_ore.apply_percent(Percent::new(99.0), RoundStrategy::Floor);
+        assert_eq!(Money::<SEK>::in_minor_unit(0), result);
+    }
+
+    #[test]
+    fn ceil_always_rounds_up() {
+        let one_ore = Money::<SEK>::in_minor_unit(1);
+        let result = one_ore.apply_percent(Percent::new(1.0), RoundStrategy::Ceil);
+        assert_eq!(Money::<SEK>::in_minor_unit(1), result);
+    }
+
+    #[test]
+    fn add_tax_adds_the_percentage_on_top() {
+        let hundred_sek = Money::<SEK>::new(100);
+        let result = hundred_sek.add_tax(Percent::new(25.0), RoundStrategy::HalfUp);
+        assert_eq!(Money::<SEK>::new(125), result);
+    }
+
+    #[test]
+    fn subtract_tax_removes_the_percentage() {
+        let hundred_sek = Money::<SEK>::new(100);
+        let result = hundred_sek.subtract_tax(Percent::new(25.0), RoundStrategy::HalfUp);
+        assert_eq!(Money::<SEK>::new(75), result);
+    }
+}
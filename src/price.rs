@@ -33,7 +33,7 @@ impl <C:Currency> PartialOrd<Money<C>> for Price<C> {
 impl<C:Currency> Price<C> {
     pub fn new(amount:MinorUnit) -> Self {
         Price{
-            minor_unit:amount*100,
+            minor_unit:amount * C::minor_unit() as MinorUnit,
             currency:PhantomData,
         }
     }